@@ -0,0 +1,19 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use bad_ring_buffer::BadRingBuffer;
+
+fn push_modulo(c: &mut Criterion) {
+    c.bench_function("push modulo wrap (capacity 1000)", |b| {
+        let mut rb = BadRingBuffer::with_capacity(1000);
+        b.iter(|| rb.push(black_box(42u64)));
+    });
+}
+
+fn push_masked(c: &mut Criterion) {
+    c.bench_function("push masked wrap (capacity 1024)", |b| {
+        let mut rb = BadRingBuffer::with_capacity_pow2(1000);
+        b.iter(|| rb.push(black_box(42u64)));
+    });
+}
+
+criterion_group!(benches, push_modulo, push_masked);
+criterion_main!(benches);
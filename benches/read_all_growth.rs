@@ -0,0 +1,40 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use bad_ring_buffer::BadRingBuffer;
+use std::io::{Read, Write};
+
+fn fill(len: usize) -> BadRingBuffer<'static, u8> {
+    let mut rb = BadRingBuffer::with_capacity(len);
+    let data = vec![0xAAu8; len];
+    rb.write_all(&data).unwrap();
+    rb
+}
+
+fn read_all_adaptive(c: &mut Criterion) {
+    c.bench_function("read_all adaptive growth (1MB)", |b| {
+        b.iter_batched(
+            || fill(1024 * 1024),
+            |mut rb| black_box(rb.read_all()),
+            criterion::BatchSize::LargeInput,
+        );
+    });
+}
+
+fn read_all_naive_per_byte(c: &mut Criterion) {
+    c.bench_function("read_all naive one-byte growth (1MB)", |b| {
+        b.iter_batched(
+            || fill(1024 * 1024),
+            |mut rb| {
+                let mut out = Vec::new();
+                let mut byte = [0u8; 1];
+                while rb.read(&mut byte).unwrap() == 1 {
+                    out.push(byte[0]);
+                }
+                black_box(out)
+            },
+            criterion::BatchSize::LargeInput,
+        );
+    });
+}
+
+criterion_group!(benches, read_all_adaptive, read_all_naive_per_byte);
+criterion_main!(benches);
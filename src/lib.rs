@@ -1,20 +1,45 @@
 use std::io::{Read, Write, Result};
-use std::alloc::{alloc, Layout};
-use std::mem::{size_of, align_of};
+use std::alloc::{alloc, dealloc, Layout};
+use std::marker::PhantomData;
+use std::mem::{size_of, align_of, MaybeUninit};
 use::std::ptr;
 
+// -----------------------------------------------------------------------------
+//    - Backing storage -
+// -----------------------------------------------------------------------------
+// Tracks how `start_ptr` was obtained so `Drop` knows how to give it back.
+enum Backing<'a, T> {
+    // A plain heap allocation made with `Layout`/`alloc`.
+    Heap(Layout),
+    // A page-aligned region mapped twice back-to-back, so the logical
+    // contents are always addressable as one contiguous slice. `mapped_len`
+    // is the size of a single mapping; the reserved region is twice that.
+    Mirrored { mapped_len: usize },
+    // An owned `Box<[MaybeUninit<T>]>` whose own `Drop` frees the memory;
+    // `start_ptr` aliases into it purely for indexing, so the field itself
+    // is never read, only held for its destructor.
+    OwnedSlice(#[allow(dead_code)] Box<[MaybeUninit<T>]>),
+    // Caller-provided storage borrowed for `'a`; nothing to free.
+    Borrowed(PhantomData<&'a mut [MaybeUninit<T>]>),
+}
+
 // -----------------------------------------------------------------------------
 //    - BadRingBuffer struct -
 // -----------------------------------------------------------------------------
-pub struct BadRingBuffer<T> {
+pub struct BadRingBuffer<'a, T> {
     head: usize,
     tail: usize,
-    start_ptr: *mut T,
+    start_ptr: *mut MaybeUninit<T>,
     capacity: usize,
     count: usize,
+    backing: Backing<'a, T>,
+    // `Some(capacity - 1)` for buffers built with `with_capacity_pow2`,
+    // letting `wrap` use a bitmask instead of `%`; every other constructor
+    // leaves it `None`, even when its capacity is a power of two.
+    mask: Option<usize>,
 }
 
-impl<T> BadRingBuffer<T> {
+impl<T> BadRingBuffer<'static, T> {
     pub fn with_capacity(capacity: usize) -> Self {
         // Define memory layout
         let layout = Layout::from_size_align(
@@ -24,16 +49,105 @@ impl<T> BadRingBuffer<T> {
 
         //Allocate memory according to defined layout
         let mem = unsafe { alloc(layout) };
-        
-        // Cast ptr to the current T size because alloc always returns a u8
-        let start_ptr = mem.cast::<T>();
+
+        // Cast ptr to the current T size because alloc always returns a u8.
+        // The allocation is uninitialized, so every slot starts life as a
+        // `MaybeUninit<T>` rather than a live `T`.
+        let start_ptr = mem.cast::<MaybeUninit<T>>();
+
+        Self {
+            head: 0,
+            tail: 0,
+            start_ptr,
+            capacity,
+            count: 0,
+            backing: Backing::Heap(layout),
+            mask: None,
+        }
+    }
+
+    // Like `with_capacity`, but rounds up to a power of two so `wrap` can
+    // use a bitmask instead of `%`.
+    pub fn with_capacity_pow2(capacity: usize) -> Self {
+        let capacity = capacity.max(1).next_power_of_two();
+        let mut rb = Self::with_capacity(capacity);
+        rb.mask = Some(capacity - 1);
+        rb
+    }
+
+    // Wraps an owned, uninitialized slice instead of allocating a fresh
+    // one; `capacity` comes from its length, and its own `Drop` frees it.
+    pub fn from_boxed_slice(mut storage: Box<[MaybeUninit<T>]>) -> Self {
+        let capacity = capacity_of(storage.len());
+        let start_ptr = storage.as_mut_ptr();
+
+        Self {
+            head: 0,
+            tail: 0,
+            start_ptr,
+            capacity,
+            count: 0,
+            backing: Backing::OwnedSlice(storage),
+            mask: None,
+        }
+    }
+
+    // Like `from_boxed_slice`, but the storage is already initialized, so
+    // its elements become the buffer's starting (full) contents instead of
+    // being discarded.
+    pub fn from_vec(storage: Vec<T>) -> Self {
+        let boxed = storage.into_boxed_slice();
+        let capacity = capacity_of(boxed.len());
+
+        // `MaybeUninit<T>` is guaranteed to share `T`'s size, alignment and
+        // ABI, so a `Box<[T]>` of already-live values can be reinterpreted
+        // as a `Box<[MaybeUninit<T>]>` in place instead of copying.
+        let raw = Box::into_raw(boxed) as *mut [MaybeUninit<T>];
+        let mut storage = unsafe { Box::from_raw(raw) };
+        let start_ptr = storage.as_mut_ptr();
+
+        Self {
+            head: 0,
+            tail: 0,
+            start_ptr,
+            capacity,
+            count: capacity,
+            backing: Backing::OwnedSlice(storage),
+            mask: None,
+        }
+    }
+}
+
+// The storage-taking constructors (`from_boxed_slice`, `from_vec` and
+// `from_slice`) derive `capacity` from the input's length; a zero-length one would make `full()` vacuously true before a
+// single element exists, which corrupts `push`'s first write.
+fn capacity_of(len: usize) -> usize {
+    assert!(len > 0, "BadRingBuffer requires non-empty storage");
+    len
+}
+
+impl<'a, T> BadRingBuffer<'a, T> {
+    // Wraps caller-provided, borrowed storage (e.g. a stack array) so the
+    // buffer needs no allocator; nothing is freed on drop since it isn't owned.
+    pub fn from_slice(storage: &'a mut [MaybeUninit<T>]) -> Self {
+        let capacity = capacity_of(storage.len());
+        let start_ptr = storage.as_mut_ptr();
 
         Self {
             head: 0,
             tail: 0,
             start_ptr,
             capacity,
-            count: 0
+            count: 0,
+            backing: Backing::Borrowed(PhantomData),
+            mask: None,
+        }
+    }
+
+    fn wrap(&self, index: usize) -> usize {
+        match self.mask {
+            Some(mask) => index & mask,
+            None => index % self.capacity,
         }
     }
 
@@ -49,55 +163,320 @@ impl<T> BadRingBuffer<T> {
         self.capacity
     }
 
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    // clippy wants a method named exactly `is_empty` alongside `len`; keep
+    // `empty()` as the primary name since it predates `len` and other code
+    // already calls it, and forward this one to it.
+    pub fn is_empty(&self) -> bool {
+        self.empty()
+    }
+
+    // Free space: how many more elements can be pushed before the oldest
+    // ones start being overwritten.
+    pub fn window(&self) -> usize {
+        self.capacity - self.count
+    }
+
     pub fn push(&mut self, value: T) {
+        let was_full = self.count == self.capacity;
+
         unsafe {
-            let next_writabel_address = self.start_ptr.offset(self.head as isize);
-            ptr::write(next_writabel_address, value);
-        }
+            let slot = self.start_ptr.add(self.head);
 
-        // if we still have room increment the count
-        if self.count < self.capacity {
-            self.count += 1;
+            // if the buffer is full we're about to overwrite the oldest
+            // still-live element, so drop it before it's clobbered
+            if was_full {
+                ptr::drop_in_place((*slot).as_mut_ptr());
+            }
+
+            (*slot).write(value);
         }
 
         // increment and wrap if needed
-        self.head = (self.head + 1) % self.capacity;
-
-        // if head has passed the tail and buffer is full ]
-        // we increment the tail to read the oldest generation
-        if self.head > self.tail && self.count == self.capacity {
-            self.tail += 1;
+        self.head = self.wrap(self.head + 1);
+
+        // overwriting the oldest element displaces it, so tail has to
+        // follow head instead of just being compared against it — a raw
+        // `self.tail += 1` (or comparing head/tail directly) desyncs after
+        // more than one lap around the buffer
+        if was_full {
+            self.tail = self.wrap(self.tail + 1);
+        } else {
+            self.count += 1;
         }
     }
 
     pub fn clear(&mut self) {
-       self.count = 0;
-       self.head = 0;
-       self.tail = 0;
+        // drop every still-live element instead of silently forgetting them
+        while self.next().is_some() {}
     }
 
     pub fn drain(&mut self) -> Vec<T> {
         let values = self.collect::<Vec<_>>();
         self.clear();
-        values 
+        values
+    }
+
+    // The oldest element, without removing it.
+    pub fn peek(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    // The element `index` slots away from `tail` (0 is the oldest), without removing it.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.count {
+            return None;
+        }
+
+        let slot = self.wrap(self.tail + index);
+        Some(unsafe { (*self.start_ptr.add(slot)).assume_init_ref() })
+    }
+
+    // Mutable counterpart of `get`.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.count {
+            return None;
+        }
+
+        let slot = self.wrap(self.tail + index);
+        Some(unsafe { (*self.start_ptr.add(slot)).assume_init_mut() })
+    }
+
+    // Iterates from oldest to newest without consuming the buffer.
+    pub fn iter(&self) -> Iter<'_, 'a, T> {
+        Iter { buffer: self, index: 0 }
+    }
+}
+
+// -----------------------------------------------------------------------------
+//    - Borrowing iterator -
+// -----------------------------------------------------------------------------
+pub struct Iter<'it, 'storage, T> {
+    buffer: &'it BadRingBuffer<'storage, T>,
+    index: usize,
+}
+
+impl<'it, 'storage, T> Iterator for Iter<'it, 'storage, T> {
+    type Item = &'it T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.buffer.get(self.index)?;
+        self.index += 1;
+        Some(value)
+    }
+}
+
+// -----------------------------------------------------------------------------
+//    - Resettable / reset -
+// -----------------------------------------------------------------------------
+// Types that can be scrubbed back to a clean state in place, as an
+// alternative to dropping and re-creating them.
+pub trait Resettable {
+    fn reset(&mut self);
+}
+
+impl<'a, T: Resettable> BadRingBuffer<'a, T> {
+    // Scrubs every still-live element via `Resettable::reset` instead of
+    // dropping it, then marks the buffer empty.
+    pub fn reset(&mut self) {
+        while self.count > 0 {
+            unsafe { (*self.start_ptr.add(self.tail)).assume_init_mut().reset() };
+            self.tail = self.wrap(self.tail + 1);
+            self.count -= 1;
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+//    - Drop impl -
+// -----------------------------------------------------------------------------
+impl<'a, T> Drop for BadRingBuffer<'a, T> {
+    fn drop(&mut self) {
+        // drop whatever live elements remain, then give the backing store
+        // back however it was obtained
+        self.clear();
+
+        match &self.backing {
+            Backing::Heap(layout) => unsafe { dealloc(self.start_ptr.cast::<u8>(), *layout) },
+            Backing::Mirrored { mapped_len } => {
+                #[cfg(target_os = "linux")]
+                unsafe { sys::munmap(self.start_ptr.cast::<std::ffi::c_void>(), *mapped_len * 2); }
+                #[cfg(not(target_os = "linux"))]
+                let _ = mapped_len;
+            }
+            // the box's own `Drop` frees the memory
+            Backing::OwnedSlice(_) => {}
+            // storage isn't owned, nothing to free
+            Backing::Borrowed(_) => {}
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+//    - raw mmap bindings (mirrored buffers) -
+// -----------------------------------------------------------------------------
+// memfd_create and MAP_ANONYMOUS's numeric value are Linux-specific; other
+// unix-family platforms (macOS, the BSDs) either lack memfd_create or define
+// these constants differently, so `cfg(unix)` would be too broad here.
+#[cfg(target_os = "linux")]
+mod sys {
+    use std::ffi::c_void;
+    use std::os::raw::{c_char, c_int, c_long, c_uint};
+
+    extern "C" {
+        pub fn memfd_create(name: *const c_char, flags: c_uint) -> c_int;
+        pub fn ftruncate(fd: c_int, length: i64) -> c_int;
+        pub fn close(fd: c_int) -> c_int;
+        pub fn mmap(addr: *mut c_void, len: usize, prot: c_int, flags: c_int, fd: c_int, offset: i64) -> *mut c_void;
+        pub fn munmap(addr: *mut c_void, len: usize) -> c_int;
+        pub fn sysconf(name: c_int) -> c_long;
+    }
+
+    pub const PROT_NONE: c_int = 0x0;
+    pub const PROT_READ: c_int = 0x1;
+    pub const PROT_WRITE: c_int = 0x2;
+    pub const MAP_SHARED: c_int = 0x01;
+    pub const MAP_PRIVATE: c_int = 0x02;
+    pub const MAP_FIXED: c_int = 0x10;
+    pub const MAP_ANONYMOUS: c_int = 0x20;
+    pub const _SC_PAGESIZE: c_int = 30;
+
+    pub fn map_failed() -> *mut c_void {
+        !0usize as *mut c_void
+    }
+}
+
+fn round_up_to_multiple(value: usize, multiple: usize) -> usize {
+    value.div_ceil(multiple) * multiple
+}
+
+// -----------------------------------------------------------------------------
+//    - Mirrored (double-mapped) constructor, u8 only -
+// -----------------------------------------------------------------------------
+// Only implemented on Linux: the constructor below is built on memfd_create,
+// which has no equivalent on other unix-family platforms, let alone Windows.
+// Calling it on an unsupported target is a compile error (no such function)
+// rather than a runtime panic.
+#[cfg(target_os = "linux")]
+impl BadRingBuffer<'static, u8> {
+    // Maps the same physical pages twice back-to-back, so the filled region
+    // is always addressable as one contiguous slice regardless of
+    // head/tail. `capacity` is rounded up to a multiple of the page size.
+    pub fn with_capacity_mirrored(capacity: usize) -> Self {
+        use self::sys::*;
+        use std::ffi::CString;
+
+        unsafe {
+            let page_size = sysconf(_SC_PAGESIZE) as usize;
+            let capacity = round_up_to_multiple(capacity.max(1), page_size);
+
+            let name = CString::new("bad_ring_buffer").unwrap();
+            let fd = memfd_create(name.as_ptr(), 0);
+            assert!(fd >= 0, "memfd_create failed");
+            assert_eq!(ftruncate(fd, capacity as i64), 0, "ftruncate failed");
+
+            // reserve a contiguous region twice the capacity so the two
+            // mappings onto the same pages land back-to-back
+            let region = mmap(ptr::null_mut(), capacity * 2, PROT_NONE, MAP_PRIVATE | MAP_ANONYMOUS, -1, 0);
+            assert_ne!(region, map_failed(), "failed to reserve mirrored address space");
+
+            let first = mmap(region, capacity, PROT_READ | PROT_WRITE, MAP_SHARED | MAP_FIXED, fd, 0);
+            assert_ne!(first, map_failed(), "failed to map first half of mirrored buffer");
+
+            let second = mmap(region.add(capacity), capacity, PROT_READ | PROT_WRITE, MAP_SHARED | MAP_FIXED, fd, 0);
+            assert_ne!(second, map_failed(), "failed to map second half of mirrored buffer");
+
+            close(fd);
+
+            Self {
+                head: 0,
+                tail: 0,
+                start_ptr: region.cast::<MaybeUninit<u8>>(),
+                capacity,
+                count: 0,
+                backing: Backing::Mirrored { mapped_len: capacity },
+                mask: None,
+            }
+        }
+    }
+}
+
+impl<'a> BadRingBuffer<'a, u8> {
+    fn mirrored_ptr(&self, offset: usize) -> Option<*mut u8> {
+        match self.backing {
+            Backing::Mirrored { .. } => Some(unsafe { self.start_ptr.add(offset).cast::<u8>() }),
+            Backing::Heap(_) | Backing::OwnedSlice(_) | Backing::Borrowed(_) => None,
+        }
+    }
+
+    // The currently filled region as one contiguous slice, if this buffer
+    // was created with `with_capacity_mirrored`.
+    pub fn as_slice(&self) -> Option<&[u8]> {
+        self.mirrored_ptr(self.tail)
+            .map(|p| unsafe { std::slice::from_raw_parts(p, self.count) })
+    }
+
+    // Mutable counterpart of `as_slice`.
+    pub fn as_mut_slice(&mut self) -> Option<&mut [u8]> {
+        let (tail, count) = (self.tail, self.count);
+        self.mirrored_ptr(tail)
+            .map(|p| unsafe { std::slice::from_raw_parts_mut(p, count) })
+    }
+
+    fn free_slice_mut(&mut self, len: usize) -> Option<&mut [u8]> {
+        let head = self.head;
+        self.mirrored_ptr(head)
+            .map(|p| unsafe { std::slice::from_raw_parts_mut(p, len) })
+    }
+
+    // Drains the whole buffer into a freshly allocated `Vec`, doubling the
+    // read reservation (up to a cap) each pass that fills it, so a tiny
+    // buffer doesn't pay for a 64K allocation up front.
+    pub fn read_all(&mut self) -> Vec<u8> {
+        const INITIAL_RESERVATION: usize = 32;
+        const MAX_RESERVATION: usize = 64 * 1024;
+
+        let mut out = Vec::new();
+        let mut reservation = INITIAL_RESERVATION;
+
+        while !self.empty() {
+            let start = out.len();
+            out.resize(start + reservation, 0);
+
+            let n = self.read(&mut out[start..]).expect("reads from a BadRingBuffer never fail");
+            out.truncate(start + n);
+
+            if n < reservation {
+                break;
+            }
+
+            reservation = (reservation * 2).min(MAX_RESERVATION);
+        }
+
+        out
     }
 }
 
 // -----------------------------------------------------------------------------
 //    - Iterator impl -
 // -----------------------------------------------------------------------------
-impl<T> Iterator for BadRingBuffer<T> {
+impl<'a, T> Iterator for BadRingBuffer<'a, T> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.empty() { 
+        if self.empty() {
             return None
         }
 
-        let p = unsafe { self.start_ptr.offset(self.tail as isize).read() };
+        // move the value out of the slot so it isn't dropped twice: once
+        // here and once when the slot is later overwritten or deallocated
+        let p = unsafe { (*self.start_ptr.add(self.tail)).assume_init_read() };
 
         // increment the tail after value read
-        self.tail = (self.tail + 1) % self.capacity;
+        self.tail = self.wrap(self.tail + 1);
 
         // decrement count after read
         self.count -= 1;
@@ -107,16 +486,26 @@ impl<T> Iterator for BadRingBuffer<T> {
 }
 
 // -----------------------------------------------------------------------------
-//    - Read imp - 
+//    - Read imp -
 // -----------------------------------------------------------------------------
-impl Read for BadRingBuffer<u8> {
-    
+impl<'a> Read for BadRingBuffer<'a, u8> {
+
     fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        // mirrored buffers expose the filled region as one contiguous
+        // slice, so the whole read can be a single copy instead of a
+        // byte-by-byte loop across the wrap boundary
+        if let Some(src) = self.as_slice() {
+            let n = src.len().min(buf.len());
+            buf[..n].copy_from_slice(&src[..n]);
+            self.tail = self.wrap(self.tail + n);
+            self.count -= n;
+            return Ok(n);
+        }
+
         let mut index = 0;
         let buf_len = buf.len();
-        while let Some(value) = self.next() {
-            buf[index] = value
-            ;
+        for value in Iterator::by_ref(self) {
+            buf[index] = value;
             index += 1;
             if index == buf_len {
                 break;
@@ -129,10 +518,21 @@ impl Read for BadRingBuffer<u8> {
 // -----------------------------------------------------------------------------
 //     - Write impl -
 // -----------------------------------------------------------------------------
-impl Write for BadRingBuffer<u8> {
+impl<'a> Write for BadRingBuffer<'a, u8> {
 
     fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
-        
+
+        // when there's room for the whole write and the buffer is mirrored,
+        // copy it in one go instead of pushing byte by byte
+        if buf.len() <= self.window() {
+            if let Some(dst) = self.free_slice_mut(buf.len()) {
+                dst.copy_from_slice(buf);
+                self.head = self.wrap(self.head + buf.len());
+                self.count += buf.len();
+                return Ok(buf.len());
+            }
+        }
+
         buf.iter().for_each(|v| self.push(*v));
 
         Ok(buf.len())
@@ -147,6 +547,8 @@ impl Write for BadRingBuffer<u8> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
 
     #[test]
     fn test_read_empty() {
@@ -196,6 +598,15 @@ mod test {
         assert_eq!(rb.next(), Some(2));
     }
 
+    #[test]
+    fn test_wrapping_write_multiple_laps_preserves_fifo_order() {
+        let mut rb = BadRingBuffer::with_capacity(2);
+        for v in 1..=6u8 {
+            rb.push(v);
+        }
+        assert_eq!(rb.drain(), vec![5, 6]);
+    }
+
     #[test]
     fn test_read() {
         let mut buf = [0; 1024];
@@ -240,4 +651,277 @@ mod test {
 
         assert!(rb.next().is_none());
     }
-}
\ No newline at end of file
+
+    // A handle that records every drop into a shared counter, so tests can
+    // assert on exactly how many times an element was destructed.
+    #[derive(Clone)]
+    struct DropCounter(Rc<RefCell<usize>>);
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            *self.0.borrow_mut() += 1;
+        }
+    }
+
+    #[test]
+    fn test_drop_on_buffer_drop_frees_remaining_elements() {
+        let count = Rc::new(RefCell::new(0));
+
+        {
+            let mut rb = BadRingBuffer::with_capacity(4);
+            rb.push(DropCounter(count.clone()));
+            rb.push(DropCounter(count.clone()));
+            rb.push(DropCounter(count.clone()));
+        }
+
+        assert_eq!(*count.borrow(), 3);
+    }
+
+    #[test]
+    fn test_drop_on_overwrite_while_wrapping() {
+        let count = Rc::new(RefCell::new(0));
+
+        {
+            let mut rb = BadRingBuffer::with_capacity(2);
+            rb.push(DropCounter(count.clone()));
+            rb.push(DropCounter(count.clone()));
+            // overwrites the oldest element above, which must be dropped
+            rb.push(DropCounter(count.clone()));
+            assert_eq!(*count.borrow(), 1);
+        }
+
+        // the two elements still live in the buffer are dropped here
+        assert_eq!(*count.borrow(), 3);
+    }
+
+    #[test]
+    fn test_drop_on_drain_does_not_double_drop() {
+        let count = Rc::new(RefCell::new(0));
+
+        let mut rb = BadRingBuffer::with_capacity(3);
+        rb.push(DropCounter(count.clone()));
+        rb.push(DropCounter(count.clone()));
+        rb.push(DropCounter(count.clone()));
+
+        let values = rb.drain();
+        assert_eq!(*count.borrow(), 0);
+
+        drop(values);
+        assert_eq!(*count.borrow(), 3);
+    }
+
+    #[test]
+    fn test_drop_on_clear_does_not_leak() {
+        let count = Rc::new(RefCell::new(0));
+
+        let mut rb = BadRingBuffer::with_capacity(3);
+        rb.push(DropCounter(count.clone()));
+        rb.push(DropCounter(count.clone()));
+        rb.clear();
+
+        assert_eq!(*count.borrow(), 2);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_mirrored_read_uses_contiguous_copy() {
+        let mut rb = BadRingBuffer::with_capacity_mirrored(1);
+        rb.write_all(&[1, 2, 3, 4]).unwrap();
+
+        let mut out = [0u8; 4];
+        let n = rb.read(&mut out).unwrap();
+        assert_eq!(n, 4);
+        assert_eq!(out, [1, 2, 3, 4]);
+        assert!(rb.empty());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_mirrored_contiguous_slice_across_wrap() {
+        let mut rb = BadRingBuffer::with_capacity_mirrored(1);
+        let cap = rb.capacity();
+
+        // fill, drain most of it, then push again so tail/head straddle
+        // the physical end of the first mapping
+        let filler = vec![0xAAu8; cap];
+        rb.write_all(&filler).unwrap();
+
+        let mut sink = vec![0u8; cap - 4];
+        rb.read_exact(&mut sink).unwrap();
+        rb.write_all(&[1, 2, 3, 4]).unwrap();
+
+        let view = rb.as_slice().expect("mirrored buffer exposes a contiguous slice");
+        assert_eq!(view.len(), 8);
+        assert_eq!(&view[0..4], &[0xAA; 4]);
+        assert_eq!(&view[4..8], &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_pow2_capacity_rounds_up() {
+        let rb = BadRingBuffer::<u8>::with_capacity_pow2(5);
+        assert_eq!(rb.capacity(), 8);
+    }
+
+    #[test]
+    fn test_pow2_wrapping_matches_modulo_wrapping() {
+        let mut rb = BadRingBuffer::with_capacity_pow2(2);
+        rb.push(0);
+        rb.push(1);
+        rb.push(2);
+        assert_eq!(rb.next(), Some(1));
+        assert_eq!(rb.next(), Some(2));
+    }
+
+    #[test]
+    fn test_peek_does_not_remove() {
+        let mut rb = BadRingBuffer::with_capacity(3);
+        rb.push(1);
+        rb.push(2);
+
+        assert_eq!(rb.peek(), Some(&1));
+        assert_eq!(rb.peek(), Some(&1));
+        assert_eq!(rb.get(1), Some(&2));
+    }
+
+    #[test]
+    fn test_get_across_wrap_boundary() {
+        let mut rb = BadRingBuffer::with_capacity(2);
+        rb.push(0);
+        rb.push(1);
+        rb.push(2);
+
+        assert_eq!(rb.get(0), Some(&1));
+        assert_eq!(rb.get(1), Some(&2));
+        assert_eq!(rb.get(2), None);
+    }
+
+    #[test]
+    fn test_get_mut_updates_in_place() {
+        let mut rb = BadRingBuffer::with_capacity(3);
+        rb.push(1);
+        rb.push(2);
+
+        if let Some(value) = rb.get_mut(1) {
+            *value = 20;
+        }
+
+        assert_eq!(rb.next(), Some(1));
+        assert_eq!(rb.next(), Some(20));
+    }
+
+    #[test]
+    fn test_iter_does_not_consume() {
+        let mut rb = BadRingBuffer::with_capacity(2);
+        rb.push(0);
+        rb.push(1);
+        rb.push(2);
+
+        let collected: Vec<&u8> = rb.iter().collect();
+        assert_eq!(collected, vec![&1, &2]);
+        assert_eq!(rb.count, 2);
+    }
+
+    #[test]
+    fn test_from_slice_uses_borrowed_storage() {
+        let mut storage = [const { MaybeUninit::uninit() }; 4];
+        let mut rb = BadRingBuffer::from_slice(&mut storage);
+
+        assert_eq!(rb.capacity(), 4);
+        rb.push(1);
+        rb.push(2);
+        assert_eq!(rb.next(), Some(1));
+        assert_eq!(rb.next(), Some(2));
+    }
+
+    #[test]
+    fn test_from_boxed_slice_derives_capacity() {
+        let storage: Box<[MaybeUninit<u8>]> = (0..4).map(|_| MaybeUninit::uninit()).collect();
+        let mut rb = BadRingBuffer::from_boxed_slice(storage);
+
+        assert_eq!(rb.capacity(), 4);
+        rb.push(1);
+        rb.push(2);
+        assert_eq!(rb.drain(), vec![1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "non-empty storage")]
+    fn test_from_slice_rejects_empty_storage() {
+        let mut storage: [MaybeUninit<u8>; 0] = [];
+        BadRingBuffer::from_slice(&mut storage);
+    }
+
+    #[test]
+    #[should_panic(expected = "non-empty storage")]
+    fn test_from_boxed_slice_rejects_empty_storage() {
+        let storage: Box<[MaybeUninit<u8>]> = Box::new([]);
+        BadRingBuffer::from_boxed_slice(storage);
+    }
+
+    #[test]
+    fn test_from_vec_starts_full_with_existing_contents() {
+        let mut rb = BadRingBuffer::from_vec(vec![1u8, 2, 3]);
+
+        assert_eq!(rb.capacity(), 3);
+        assert!(rb.full());
+        assert_eq!(rb.drain(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_window_shrinks_and_grows() {
+        let mut rb = BadRingBuffer::with_capacity(3);
+        assert_eq!(rb.window(), 3);
+
+        rb.push(1);
+        assert_eq!(rb.len(), 1);
+        assert_eq!(rb.window(), 2);
+
+        rb.push(2);
+        assert_eq!(rb.window(), 1);
+
+        rb.next();
+        assert_eq!(rb.len(), 1);
+        assert_eq!(rb.window(), 2);
+    }
+
+    #[derive(PartialEq, Debug)]
+    struct Packet(Vec<u8>);
+
+    impl Resettable for Packet {
+        fn reset(&mut self) {
+            self.0.clear();
+        }
+    }
+
+    #[test]
+    fn test_reset_scrubs_elements_and_empties_buffer() {
+        let mut rb = BadRingBuffer::with_capacity(2);
+        rb.push(Packet(vec![1, 2, 3]));
+        rb.push(Packet(vec![4, 5]));
+
+        rb.reset();
+
+        assert!(rb.empty());
+        assert_eq!(rb.len(), 0);
+        assert_eq!(rb.next(), None);
+    }
+
+    #[test]
+    fn test_read_all_tiny_contents() {
+        let mut rb = BadRingBuffer::with_capacity(8);
+        rb.write_all(&[1, 2, 3]).unwrap();
+
+        assert_eq!(rb.read_all(), vec![1, 2, 3]);
+        assert!(rb.empty());
+    }
+
+    #[test]
+    fn test_read_all_large_contents_ramp_past_initial_reservation() {
+        let data: Vec<u8> = (0..200_000u32).map(|n| n as u8).collect();
+        let mut rb = BadRingBuffer::with_capacity(data.len());
+        rb.write_all(&data).unwrap();
+
+        assert_eq!(rb.read_all(), data);
+        assert!(rb.empty());
+    }
+}